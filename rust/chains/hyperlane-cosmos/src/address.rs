@@ -0,0 +1,132 @@
+use std::str::FromStr;
+
+use bech32::FromBase32;
+use hyperlane_core::{ChainCommunicationError, ChainResult, H256};
+
+/// A Cosmos account address.
+///
+/// cw-hyperlane contracts have moved some ISM and validator-announce
+/// deployments to an "ethereum-style address scheme" where addresses are
+/// raw 20-byte values rather than the bech32 encoding the rest of the
+/// Cosmos SDK uses. This type accepts either encoding and normalizes both
+/// to the same canonical `H256` digest the mailbox expects.
+#[derive(Debug, Clone)]
+pub struct CosmosAddress {
+    bytes: Vec<u8>,
+    digest: H256,
+}
+
+impl CosmosAddress {
+    /// The canonical `H256` digest of this address.
+    pub fn digest(&self) -> H256 {
+        self.digest
+    }
+
+    /// The raw account bytes backing this address (20 bytes for an
+    /// ethereum-style address; a bech32 address may decode to any length
+    /// up to 32 bytes, e.g. a 32-byte contract address).
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn from_account_bytes(bytes: Vec<u8>) -> ChainResult<Self> {
+        let digest = account_bytes_to_h256(&bytes)?;
+        Ok(Self { bytes, digest })
+    }
+}
+
+/// Left-pads account bytes into an `H256`, matching how the mailbox
+/// represents non-EVM addresses.
+fn account_bytes_to_h256(bytes: &[u8]) -> ChainResult<H256> {
+    if bytes.len() > H256::len_bytes() {
+        return Err(ChainCommunicationError::from_other_str(&format!(
+            "address has {} bytes, expected at most {}",
+            bytes.len(),
+            H256::len_bytes()
+        )));
+    }
+
+    let mut digest = H256::zero();
+    let start = H256::len_bytes() - bytes.len();
+    digest.as_mut()[start..].copy_from_slice(bytes);
+    Ok(digest)
+}
+
+/// Parses a plain or `0x`-prefixed 20-byte hex address, as returned by
+/// contracts using the ethereum-style address scheme.
+fn parse_ethereum_style_address(address: &str) -> ChainResult<Vec<u8>> {
+    let stripped = address.strip_prefix("0x").unwrap_or(address);
+    let bytes = hex::decode(stripped)
+        .map_err(|e| ChainCommunicationError::from_other_str(&e.to_string()))?;
+
+    if bytes.len() != 20 {
+        return Err(ChainCommunicationError::from_other_str(&format!(
+            "expected a 20-byte ethereum-style address, got {} bytes",
+            bytes.len()
+        )));
+    }
+
+    Ok(bytes)
+}
+
+/// Parses a bech32-encoded Cosmos account address.
+fn parse_bech32_address(address: &str) -> ChainResult<Vec<u8>> {
+    let (_hrp, data, _variant) = bech32::decode(address)
+        .map_err(|e| ChainCommunicationError::from_other_str(&e.to_string()))?;
+
+    Vec::<u8>::from_base32(&data)
+        .map_err(|e| ChainCommunicationError::from_other_str(&e.to_string()))
+}
+
+impl FromStr for CosmosAddress {
+    type Err = ChainCommunicationError;
+
+    /// Accepts either a bech32-encoded account address or a `0x`-prefixed /
+    /// plain 20-byte hex address, trying the ethereum-style encoding first
+    /// since it is unambiguous (bech32 strings are never valid hex).
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let bytes =
+            parse_ethereum_style_address(address).or_else(|_| parse_bech32_address(address))?;
+        Self::from_account_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn expected_digest(account_bytes_hex: &str) -> H256 {
+        let bytes = hex::decode(account_bytes_hex).unwrap();
+        let mut digest = H256::zero();
+        digest.as_mut()[H256::len_bytes() - bytes.len()..].copy_from_slice(&bytes);
+        digest
+    }
+
+    #[test]
+    fn parses_0x_prefixed_ethereum_style_address() {
+        let account = "1234567890123456789012345678901234567890";
+        let parsed = CosmosAddress::from_str(&format!("0x{account}")).unwrap();
+        assert_eq!(parsed.bytes().len(), 20);
+        assert_eq!(parsed.digest(), expected_digest(account));
+    }
+
+    #[test]
+    fn parses_plain_hex_ethereum_style_address() {
+        let account = "1234567890123456789012345678901234567890";
+        let parsed = CosmosAddress::from_str(account).unwrap();
+        assert_eq!(parsed.bytes().len(), 20);
+        assert_eq!(parsed.digest(), expected_digest(account));
+    }
+
+    #[test]
+    fn parses_bech32_address() {
+        // bech32 encoding of the same account bytes used by the
+        // ethereum-style address tests above, so the digest assertion
+        // exercises the same known value.
+        let account = "1234567890123456789012345678901234567890";
+        let address = "osmo1zg69v7yszg69v7yszg69v7yszg69v7ysaqj6kx";
+        let parsed = CosmosAddress::from_str(address).unwrap();
+        assert_eq!(parsed.bytes().len(), 20);
+        assert_eq!(parsed.digest(), expected_digest(account));
+    }
+}
@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use cosmwasm_std::HexBinary;
+use hpl_interface::ism::aggregate::{AggregationIsmQueryMsg, IsmsAndThresholdResponse, QueryMsg};
+use hyperlane_core::{
+    AggregationIsm, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, RawHyperlaneMessage, H256,
+};
+
+use crate::{
+    address::CosmosAddress,
+    grpc::{WasmGrpcProvider, WasmProvider},
+    signers::Signer,
+    ConnectionConf, CosmosProvider,
+};
+
+/// A reference to an AggregationIsm contract on some Cosmos chain
+#[derive(Debug)]
+pub struct CosmosAggregationIsm {
+    domain: HyperlaneDomain,
+    address: H256,
+    provider: Box<WasmGrpcProvider>,
+}
+
+impl CosmosAggregationIsm {
+    /// create a new instance of CosmosAggregationIsm
+    pub fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator,
+        signer: Option<Signer>,
+    ) -> ChainResult<Self> {
+        let provider = WasmGrpcProvider::new(conf.clone(), locator.clone(), signer)?;
+
+        Ok(Self {
+            domain: locator.domain.clone(),
+            address: locator.address,
+            provider: Box::new(provider),
+        })
+    }
+}
+
+impl HyperlaneContract for CosmosAggregationIsm {
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl HyperlaneChain for CosmosAggregationIsm {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(CosmosProvider::new(self.domain.clone()))
+    }
+}
+
+#[async_trait]
+impl AggregationIsm for CosmosAggregationIsm {
+    async fn modules_and_threshold(
+        &self,
+        message: &HyperlaneMessage,
+    ) -> ChainResult<(Vec<H256>, u8)> {
+        let payload = QueryMsg::AggregationIsm(AggregationIsmQueryMsg::ModulesAndThreshold {
+            message: HexBinary::from(RawHyperlaneMessage::from(message)),
+        });
+        let data = self.provider.wasm_query(payload, None).await?;
+        let response: IsmsAndThresholdResponse = serde_json::from_slice(&data)?;
+
+        let isms = response
+            .isms
+            .iter()
+            .map(|ism| CosmosAddress::from_str(ism).map(|addr| addr.digest()))
+            .collect::<ChainResult<Vec<H256>>>()?;
+
+        Ok((isms, response.threshold))
+    }
+}
@@ -0,0 +1,285 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::{Coin, HexBinary};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneProvider, Indexer, LogMeta, TxOutcome, H256, H512, U256,
+};
+
+use crate::{
+    address::CosmosAddress,
+    grpc::{WasmGrpcProvider, WasmProvider},
+    signers::Signer,
+    ConnectionConf, CosmosProvider,
+};
+
+/// A remote-transfer event emitted by a cw-hyperlane token router (warp
+/// route) contract, either a cw20 collateral/synthetic router or a
+/// native-denom collateral router.
+#[derive(Debug, Clone)]
+pub struct CosmosTokenTransfer {
+    /// the id of the hyperlane message the transfer was dispatched under
+    pub message_id: H256,
+    /// the domain the tokens were sent to
+    pub destination: u32,
+    /// the recipient on the destination domain
+    pub recipient: H256,
+    /// the amount transferred, denominated in the router's token
+    pub amount: U256,
+}
+
+/// Execution payload for a token router's `transfer_remote` entry point.
+/// cw20-backed routers require no `funds`; native-denom collateral routers
+/// attach the transferred amount as `funds` on the execute message, which
+/// `WasmProvider::wasm_send_with_funds` threads through from the caller.
+///
+/// `amount` is encoded as a decimal string, matching the `Uint256` wire
+/// format cw-hyperlane's token router contracts expect (`hyperlane_core`'s
+/// `U256` serializes to a JSON number by default, which the contract would
+/// reject/misparse).
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenRouterExecuteMsg {
+    TransferRemote {
+        dest_domain: u32,
+        recipient: HexBinary,
+        #[serde(with = "amount_as_dec_str")]
+        amount: U256,
+    },
+}
+
+mod amount_as_dec_str {
+    use hyperlane_core::U256;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(amount: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&amount.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        U256::from_dec_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenRouterQueryMsg {
+    EnrolledRouter { dest_domain: u32 },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EnrolledRouterResponse {
+    pub router: Option<String>,
+}
+
+/// A reference to a warp-route token router contract on some Cosmos chain,
+/// covering both cw20 and native-denom collateral modes.
+#[derive(Debug)]
+pub struct CosmosTokenRouter {
+    domain: HyperlaneDomain,
+    address: H256,
+    provider: Box<WasmGrpcProvider>,
+}
+
+impl CosmosTokenRouter {
+    /// create a new instance of CosmosTokenRouter
+    pub fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator,
+        signer: Option<Signer>,
+    ) -> ChainResult<Self> {
+        let provider = WasmGrpcProvider::new(conf.clone(), locator.clone(), signer)?;
+
+        Ok(Self {
+            domain: locator.domain.clone(),
+            address: locator.address,
+            provider: Box::new(provider),
+        })
+    }
+
+    /// Returns the remote router enrolled for `domain`, if any, normalized
+    /// to an `H256` the same way ISM and validator-announce addresses are.
+    pub async fn enrolled_router(&self, domain: u32) -> ChainResult<Option<H256>> {
+        let payload = TokenRouterQueryMsg::EnrolledRouter {
+            dest_domain: domain,
+        };
+        let data = self.provider.wasm_query(payload, None).await?;
+        let response: EnrolledRouterResponse = serde_json::from_slice(&data)?;
+
+        response
+            .router
+            .map(|r| CosmosAddress::from_str(&r).map(|a| a.digest()))
+            .transpose()
+    }
+
+    /// Sends `amount` of the router's token to `recipient` on `domain`.
+    /// cw20-backed routers pass `collateral_funds: None`; native-denom
+    /// collateral routers must pass `Some(Coin { denom, amount })` matching
+    /// `amount`, which is attached as `funds` on the execute message.
+    pub async fn transfer_remote(
+        &self,
+        domain: u32,
+        recipient: H256,
+        amount: U256,
+        collateral_funds: Option<Coin>,
+        tx_gas_limit: Option<U256>,
+    ) -> ChainResult<TxOutcome> {
+        let payload = TokenRouterExecuteMsg::TransferRemote {
+            dest_domain: domain,
+            recipient: HexBinary::from(recipient.as_bytes()),
+            amount,
+        };
+
+        let funds = collateral_funds.into_iter().collect::<Vec<_>>();
+        let response: TxResponse = self
+            .provider
+            .wasm_send_with_funds(payload, funds, tx_gas_limit)
+            .await?;
+
+        Ok(TxOutcome::try_from_tx_response(response)?)
+    }
+}
+
+impl HyperlaneContract for CosmosTokenRouter {
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl HyperlaneChain for CosmosTokenRouter {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(CosmosProvider::new(self.domain.clone()))
+    }
+}
+
+const TRANSFER_REMOTE_EVENT_TYPE: &str = "wasm-transfer_remote";
+
+/// An indexer over a token router's remote-transfer events, letting
+/// warp-route relaying and balance checks work against Cosmos chains the
+/// same way they do on EVM.
+#[derive(Debug)]
+pub struct CosmosTokenRouterIndexer {
+    address: H256,
+    provider: Box<WasmGrpcProvider>,
+}
+
+impl CosmosTokenRouterIndexer {
+    /// create a new instance of CosmosTokenRouterIndexer
+    pub fn new(
+        conf: ConnectionConf,
+        locator: ContractLocator,
+        signer: Option<Signer>,
+    ) -> ChainResult<Self> {
+        let provider = WasmGrpcProvider::new(conf, locator.clone(), signer)?;
+
+        Ok(Self {
+            address: locator.address,
+            provider: Box::new(provider),
+        })
+    }
+
+    fn parse_transfer(
+        &self,
+        attrs: &[(String, String)],
+        block_height: u64,
+        block_hash: H256,
+        tx_hash: H512,
+        tx_index: u32,
+        log_index: U256,
+    ) -> ChainResult<(CosmosTokenTransfer, LogMeta)> {
+        let mut message_id = None;
+        let mut destination = None;
+        let mut recipient = None;
+        let mut amount = None;
+
+        for (key, value) in attrs {
+            match key.as_str() {
+                "message_id" => message_id = Some(H256::from_str(value)?),
+                "dest_domain" => {
+                    destination = Some(
+                        value
+                            .parse::<u32>()
+                            .map_err(|e| ChainCommunicationError::from_other_str(&e.to_string()))?,
+                    )
+                }
+                // The destination-domain recipient is a full 32-byte address
+                // (e.g. an EVM-padded address), not a local Cosmos account,
+                // so it's parsed as a raw H256 rather than a CosmosAddress.
+                "recipient" => recipient = Some(H256::from_str(value)?),
+                "amount" => {
+                    amount = Some(
+                        U256::from_dec_str(value)
+                            .map_err(|e| ChainCommunicationError::from_other_str(&e.to_string()))?,
+                    )
+                }
+                _ => {}
+            }
+        }
+
+        let transfer = CosmosTokenTransfer {
+            message_id: message_id
+                .ok_or_else(|| ChainCommunicationError::from_other_str("missing message_id"))?,
+            destination: destination
+                .ok_or_else(|| ChainCommunicationError::from_other_str("missing dest_domain"))?,
+            recipient: recipient
+                .ok_or_else(|| ChainCommunicationError::from_other_str("missing recipient"))?,
+            amount: amount
+                .ok_or_else(|| ChainCommunicationError::from_other_str("missing amount"))?,
+        };
+
+        let meta = LogMeta {
+            address: self.address,
+            block_number: block_height,
+            block_hash,
+            transaction_id: tx_hash.into(),
+            transaction_index: tx_index,
+            log_index,
+        };
+
+        Ok((transfer, meta))
+    }
+}
+
+#[async_trait]
+impl Indexer<CosmosTokenTransfer> for CosmosTokenRouterIndexer {
+    async fn fetch_logs(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(CosmosTokenTransfer, LogMeta)>> {
+        let txs = self
+            .provider
+            .wasm_txs_in_range(range, TRANSFER_REMOTE_EVENT_TYPE)
+            .await?;
+
+        let mut transfers = Vec::new();
+        for tx in txs {
+            for (log_index, attrs) in tx.events.iter().enumerate() {
+                let (transfer, meta) = self.parse_transfer(
+                    attrs,
+                    tx.block_height,
+                    tx.block_hash,
+                    tx.tx_hash,
+                    tx.tx_index,
+                    U256::from(log_index as u64),
+                )?;
+                transfers.push((transfer, meta));
+            }
+        }
+
+        Ok(transfers)
+    }
+
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        self.provider.latest_block_height().await
+    }
+}
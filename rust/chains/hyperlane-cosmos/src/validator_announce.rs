@@ -12,7 +12,7 @@ use crate::{
     grpc::{WasmGrpcProvider, WasmProvider},
     payloads::validator_announce::{AnnouncementRequest, AnnouncementRequestInner},
     signers::Signer,
-    validator_announce, ConnectionConf, CosmosProvider,
+    ConnectionConf, CosmosProvider,
 };
 
 /// A reference to a ValidatorAnnounce contract on some Cosmos chain
@@ -21,6 +21,8 @@ pub struct CosmosValidatorAnnounce {
     domain: HyperlaneDomain,
     address: H256,
     provider: Box<WasmGrpcProvider>,
+    conf: ConnectionConf,
+    signer: Option<Signer>,
 }
 
 impl CosmosValidatorAnnounce {
@@ -30,12 +32,14 @@ impl CosmosValidatorAnnounce {
         locator: ContractLocator,
         signer: Option<Signer>,
     ) -> ChainResult<Self> {
-        let provider = WasmGrpcProvider::new(conf.clone(), locator.clone(), signer)?;
+        let provider = WasmGrpcProvider::new(conf.clone(), locator.clone(), signer.clone())?;
 
         Ok(Self {
             domain: locator.domain.clone(),
             address: locator.address,
             provider: Box::new(provider),
+            conf,
+            signer,
         })
     }
 }
@@ -101,8 +105,31 @@ impl ValidatorAnnounce for CosmosValidatorAnnounce {
     }
 
     async fn announce_tokens_needed(&self, announcement: SignedType<Announcement>) -> Option<U256> {
-        // TODO: check user balance. For now, just try announcing and
-        // allow the announce attempt to fail if there are not enough tokens.
-        Some(0u64.into())
+        let signer = self.signer.as_ref()?;
+
+        let announce_request = AnnouncementRequest {
+            announce: AnnouncementRequestInner {
+                validator: hex::encode(announcement.value.validator),
+                storage_location: announcement.value.storage_location,
+                signature: hex::encode(announcement.signature.to_vec()),
+            },
+        };
+
+        let gas_used = self
+            .provider
+            .wasm_estimate_gas(announce_request)
+            .await
+            .ok()?;
+
+        let gas_price = self.conf.get_minimum_gas_price();
+        let max_cost = U256::from(gas_used) * gas_price;
+
+        let balance = self
+            .provider
+            .account_balance(&signer.address, &self.conf.get_fee_denom())
+            .await
+            .ok()?;
+
+        Some(max_cost.saturating_sub(balance))
     }
 }